@@ -15,24 +15,87 @@ NOTES:
   - To prevent the deployed contract from being modified or deleted, it should not have any access
     keys on its account.
 */
+use near_contract_standards::fungible_token::core::FungibleTokenCore;
 use near_contract_standards::fungible_token::metadata::{
     FungibleTokenMetadata, FungibleTokenMetadataProvider, FT_METADATA_SPEC,
 };
+use near_contract_standards::fungible_token::resolver::FungibleTokenResolver;
 use near_contract_standards::fungible_token::FungibleToken;
+use near_contract_standards::storage_management::{
+    StorageBalance, StorageBalanceBounds, StorageManagement,
+};
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::LazyOption;
+use near_sdk::collections::{LazyOption, LookupMap};
 use near_sdk::json_types::{ValidAccountId, U128};
-use near_sdk::{env, log, near_bindgen, AccountId, Balance, PanicOnDefault, PromiseOrValue};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{
+    assert_one_yocto, env, log, near_bindgen, AccountId, Balance, Gas, PanicOnDefault, Promise,
+    PromiseOrValue,
+};
+use std::collections::HashSet;
 
 near_sdk::setup_alloc!();
 
+/// A permission that can be granted to an account via [`Contract::grant_role`].
+#[derive(
+    BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug,
+)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    /// Can grant and revoke roles, including `Admin` itself.
+    Admin,
+    /// Can call `ft_mint`.
+    Minter,
+    /// Can call `ft_burn`.
+    Burner,
+}
+
+/// NEP-297 event log, serialized to the standardized
+/// `EVENT_JSON:{"standard":"nep141","version":"1.0.0","event":"...","data":[...]}`
+/// envelope via [`FtEvent::emit`].
+#[derive(Serialize, Debug, Clone)]
+#[serde(crate = "near_sdk::serde", untagged)]
+pub enum FtEvent {
+    FtMint { owner_id: AccountId, amount: U128 },
+    FtBurn { owner_id: AccountId, amount: U128 },
+    FtTransfer { old_owner_id: AccountId, new_owner_id: AccountId, amount: U128, memo: Option<String> },
+}
+
+impl FtEvent {
+    fn name(&self) -> &'static str {
+        match self {
+            FtEvent::FtMint { .. } => "ft_mint",
+            FtEvent::FtBurn { .. } => "ft_burn",
+            FtEvent::FtTransfer { .. } => "ft_transfer",
+        }
+    }
+
+    /// Logs `self` as a NEP-297 event so indexers can parse it.
+    pub fn emit(&self) {
+        let envelope = near_sdk::serde_json::json!({
+            "standard": "nep141",
+            "version": "1.0.0",
+            "event": self.name(),
+            "data": [self],
+        });
+        log!("EVENT_JSON:{}", envelope);
+    }
+}
+
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
 pub struct Contract {
     token: FungibleToken,
     metadata: LazyOption<FungibleTokenMetadata>,
+    roles: LookupMap<AccountId, HashSet<Role>>,
+    paused: bool,
+    owner_id: AccountId,
 }
 
+/// Gas that `upgrade` holds back from its prepaid gas for its own execution
+/// and the `deploy_contract` action; the rest goes to the chained `migrate` call.
+const USED_GAS: Gas = 20_000_000_000_000;
+
 const DATA_IMAGE_SVG_CHC_ICON: &str = "data:image/svg+xml,%3Csvg width='5e3' height='3e3' version='1.1' viewBox='0 0 5e3 3e3' xmlns='http://www.w3.org/2000/svg'%3E%3Cpath d='m1e3 2e3c-402-13-755-381-745-784-2-3%0A42 241-673 576-757 270-75 577 7 771 210 92 58 68 163 15 237-119 270-270 525-394 793 137-319 333-611 452-938-225-334-728-432-1e3 -205-301 192-443 6%0A03-307 936 117 309 441 532 774 505 211-12 424-108 549-283 63-134 117-273 177-409-84 156-127 333-227 480-153 147-369 226-580 214zm-43-231c-85 20-6-%0A170-41-58-5 107 159 55 120-34 9-34-13 165-79 92zm264-61c74 11 184 70 233-18 45-120 109-232 162-349 20-41 39-80 8-15-67 141-137 280-202 421-69-0.9-%0A133-32-201-40zm-443 14c-87 5 2-147-100-135-90-55-154-153-162-259 15 126 101 245 223 287-44 81 83 180 105 61 10-34-17 114-65 46zm137-67c-13-7 20 5 0 0zm-48-8c33 4 7 0.9 0 0zm191 3c31-9 19-2 0 0zm48-22c4-17 0.01 13 0 0zm0.7-12c9-11-11 44 0 0zm-160-141c14 4 22 7 0 0zm173-5c-0.8 45-8 54-0.3 3zm-%0A143 5-18-0.6zm104-3c-9 1-6 0.7 0 0zm-270-37c59 18 72 22 0 0zm-29-19c7-39-63-63-9-20 19 13 40 46 9 20zm1e3 -108c-4-32-68-56-9-32 7 3 18 79 9 32zm-1%0Ae3 2c12 40 6 51 0 0zm-0.9-3c2-7 0.6 10 0 0zm-221-3c-2-11 3 4 0 0zm-0.02-9 0.04 5zm221-5c0.6 31 8 25 0 0zm-222 0.3c-110-161-219-321-329-482 110 161 219 321 329 482zm225-51c-4 19-1 58 0.08 16zm-225 45c4-36 6-64 1-11zm221-9c5-23 0.3-32 0 0zm985-29c65 18-33-5 0 0zm-1e3 -59c-8 53-5 50 0 0zm6-29 1%0A-0.5-1 0.5zm1-3 1-0.4zm0.8-2c42-153 150-276 268-377 92-27 189-64 226-153 50-22 101 102 53 18-59-42-103 35-117 72-92 43-199 66-261 153-77 82-131 18%0A2-170 287zm364-201c25-29 136-63 51-31-73 38-116 112-164 176 30-54 70-102 113-145zm848 137c50-104 109-203 154-309-55 101-103 206-154 309zm-0.9 2c21%0A-25 98-124 81-101-27 34-54 67-81 101zm-410-108c-19-29-137-77-109-67 39 18 77 39 109 67zm8 3c23-31 100-136 91-117-29 40-59 79-91 117zm-165-87c-54-4%0A-52-14 0 0zm-148-7c20-20 14 7 0 0zm21-4c33-1 20 4 0 0zm399-48c-59-60-165-70-189-160-34-16-127 19-43-10 74 30 108 116 190 139 14 10 28 20 42 30zm-2%0A90-130c-40-5 9-3 3-1zm-21-4c9 9-24-9 0 0zm-31-1c2-7 13 12 0 0zm-12-1c5-4 8 12 0 0z' stroke-width='.8'/%3E%3C/svg%3E";
 
 
@@ -50,7 +113,7 @@ impl Contract {
                 name: "ChaveroCoin Token".to_string(),
                 symbol: "ChC".to_string(),
                 icon: Some(DATA_IMAGE_SVG_CHC_ICON.to_string()),
-                reference: Some("https://chaverocoin.com/token.json".to_string()),
+                reference: None,
                 reference_hash: None,
                 decimals: 24,
             },
@@ -70,12 +133,45 @@ impl Contract {
         let mut this = Self {
             token: FungibleToken::new(b"a".to_vec()),
             metadata: LazyOption::new(b"m".to_vec(), Some(&metadata)),
+            roles: LookupMap::new(b"r".to_vec()),
+            paused: false,
+            owner_id: owner_id.clone().into(),
         };
         this.token.internal_register_account(owner_id.as_ref());
         this.token.internal_deposit(owner_id.as_ref(), total_supply.into());
+        this.roles.insert(owner_id.as_ref(), &[Role::Admin].iter().copied().collect());
+        FtEvent::FtMint { owner_id: owner_id.into(), amount: total_supply }.emit();
         this
     }
 
+    /// Deploys `code` (passed as the raw call input) to this contract's own
+    /// account and chains a call to `migrate` so storage survives the code
+    /// swap. Restricted to `owner_id`, since the account deliberately has no
+    /// access keys and this is the only way to ship a fix.
+    pub fn upgrade(&self) {
+        assert_eq!(
+            env::predecessor_account_id(),
+            self.owner_id,
+            "Only the owner can upgrade this contract"
+        );
+        assert!(env::prepaid_gas() > USED_GAS, "Not enough gas attached");
+        let code = env::input().expect("Error: No input").to_vec();
+        Promise::new(env::current_account_id()).deploy_contract(code).function_call(
+            b"migrate".to_vec(),
+            vec![],
+            0,
+            env::prepaid_gas() - USED_GAS,
+        );
+    }
+
+    /// Re-initializes the contract after `upgrade` deploys new code,
+    /// restoring state from the previous layout so storage survives the
+    /// swap.
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        env::state_read().expect("Failed to read the old contract state")
+    }
+
     fn on_account_closed(&mut self, account_id: AccountId, balance: Balance) {
         log!("Closed @{} with {}", account_id, balance);
     }
@@ -83,10 +179,226 @@ impl Contract {
     fn on_tokens_burned(&mut self, account_id: AccountId, amount: Balance) {
         log!("Account @{} burned {}", account_id, amount);
     }
+
+    /// Mints ChaveroCoin 1:1 for the attached NEAR deposit, registering the
+    /// predecessor in the token if it isn't already (the storage cost for
+    /// registration is taken out of the attached deposit).
+    #[payable]
+    pub fn near_deposit(&mut self) {
+        assert!(!self.paused, "Transfers are paused");
+        let account_id = env::predecessor_account_id();
+        let mut amount = env::attached_deposit();
+
+        if !self.token.accounts.contains_key(&account_id) {
+            let storage_cost = self.token.storage_balance_bounds().min.0;
+            assert!(
+                amount >= storage_cost,
+                "Attached deposit of {} is less than the storage cost of {} required to register",
+                amount,
+                storage_cost
+            );
+            self.token.internal_register_account(&account_id);
+            amount -= storage_cost;
+        }
+
+        self.token.internal_deposit(&account_id, amount);
+        log!("Deposited {} yoctoNEAR, minted {} ChC to @{}", amount, amount, account_id);
+    }
+
+    /// Burns `amount` ChaveroCoin from the predecessor and returns the same
+    /// amount of native NEAR. Requires exactly 1 yoctoNEAR attached so that
+    /// this can only be called via a full access key, matching the security
+    /// model of `ft_transfer`.
+    #[payable]
+    pub fn near_withdraw(&mut self, amount: U128) {
+        assert!(!self.paused, "Transfers are paused");
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        self.token.internal_withdraw(&account_id, amount.0);
+        Promise::new(account_id).transfer(amount.0);
+    }
+
+    /// Grants `role` to `account_id`. Restricted to accounts holding `Admin`.
+    pub fn grant_role(&mut self, account_id: ValidAccountId, role: Role) {
+        self.require_role(&env::predecessor_account_id(), Role::Admin);
+        let mut roles = self.roles.get(account_id.as_ref()).unwrap_or_default();
+        roles.insert(role);
+        self.roles.insert(account_id.as_ref(), &roles);
+    }
+
+    /// Revokes `role` from `account_id`. Restricted to accounts holding `Admin`.
+    pub fn revoke_role(&mut self, account_id: ValidAccountId, role: Role) {
+        self.require_role(&env::predecessor_account_id(), Role::Admin);
+        if let Some(mut roles) = self.roles.get(account_id.as_ref()) {
+            roles.remove(&role);
+            self.roles.insert(account_id.as_ref(), &roles);
+        }
+    }
+
+    /// Mints `amount` ChaveroCoin to `account_id`, registering it in the
+    /// token first if it isn't already. Restricted to accounts holding
+    /// `Minter`.
+    pub fn ft_mint(&mut self, account_id: ValidAccountId, amount: U128) {
+        assert!(!self.paused, "Transfers are paused");
+        self.require_role(&env::predecessor_account_id(), Role::Minter);
+        let account_id: AccountId = account_id.into();
+        if !self.token.accounts.contains_key(&account_id) {
+            self.token.internal_register_account(&account_id);
+        }
+        self.token.internal_deposit(&account_id, amount.0);
+        FtEvent::FtMint { owner_id: account_id, amount }.emit();
+    }
+
+    /// Burns `amount` ChaveroCoin from the predecessor. Restricted to
+    /// accounts holding `Burner`.
+    pub fn ft_burn(&mut self, amount: U128) {
+        assert!(!self.paused, "Transfers are paused");
+        let account_id = env::predecessor_account_id();
+        self.require_role(&account_id, Role::Burner);
+        self.token.internal_withdraw(&account_id, amount.0);
+        self.on_tokens_burned(account_id.clone(), amount.0);
+        FtEvent::FtBurn { owner_id: account_id, amount }.emit();
+    }
+
+    /// Freezes `ft_transfer`, `ft_transfer_call`, `storage_withdraw`,
+    /// `near_deposit`, `near_withdraw`, `ft_mint` and `ft_burn`;
+    /// `storage_deposit` and `storage_unregister` are not blocked.
+    /// Restricted to accounts holding `Admin`.
+    pub fn pause(&mut self) {
+        self.require_role(&env::predecessor_account_id(), Role::Admin);
+        self.paused = true;
+    }
+
+    /// Lifts a freeze put in place by `pause`. Restricted to accounts
+    /// holding `Admin`.
+    pub fn unpause(&mut self) {
+        self.require_role(&env::predecessor_account_id(), Role::Admin);
+        self.paused = false;
+    }
+
+    /// Returns whether transfers are currently frozen.
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    fn has_role(&self, account_id: &AccountId, role: &Role) -> bool {
+        self.roles.get(account_id).is_some_and(|roles| roles.contains(role))
+    }
+
+    fn require_role(&self, account_id: &AccountId, role: Role) {
+        assert!(
+            self.has_role(account_id, &role),
+            "Account @{} is missing the {:?} role",
+            account_id,
+            role
+        );
+    }
+}
+
+// Transfers (and storage withdrawals) must be blockable by `pause`, so the core/storage trait impls
+// are written out by hand instead of via `impl_fungible_token_core!` /
+// `impl_fungible_token_storage!`, with a `paused` check added to
+// `ft_transfer`, `ft_transfer_call` and `storage_withdraw`. View methods keep delegating straight through
+// so they stay callable while paused.
+#[near_bindgen]
+impl FungibleTokenCore for Contract {
+    #[payable]
+    fn ft_transfer(&mut self, receiver_id: ValidAccountId, amount: U128, memo: Option<String>) {
+        assert!(!self.paused, "Transfers are paused");
+        let old_owner_id = env::predecessor_account_id();
+        let new_owner_id: AccountId = receiver_id.clone().into();
+        self.token.ft_transfer(receiver_id, amount, memo.clone());
+        FtEvent::FtTransfer { old_owner_id, new_owner_id, amount, memo }.emit();
+    }
+
+    #[payable]
+    fn ft_transfer_call(
+        &mut self,
+        receiver_id: ValidAccountId,
+        amount: U128,
+        memo: Option<String>,
+        msg: String,
+    ) -> PromiseOrValue<U128> {
+        assert!(!self.paused, "Transfers are paused");
+        // The receiver's `ft_on_transfer` may refund part or all of `amount`
+        // once it resolves, so the event is emitted from `ft_resolve_transfer`
+        // with the actually-settled amount instead of here.
+        self.token.ft_transfer_call(receiver_id, amount, memo, msg)
+    }
+
+    fn ft_total_supply(&self) -> U128 {
+        self.token.ft_total_supply()
+    }
+
+    fn ft_balance_of(&self, account_id: ValidAccountId) -> U128 {
+        self.token.ft_balance_of(account_id)
+    }
+}
+
+#[near_bindgen]
+impl FungibleTokenResolver for Contract {
+    #[private]
+    fn ft_resolve_transfer(
+        &mut self,
+        sender_id: ValidAccountId,
+        receiver_id: ValidAccountId,
+        amount: U128,
+    ) -> U128 {
+        let sender_id: AccountId = sender_id.into();
+        let new_owner_id: AccountId = receiver_id.clone().into();
+        let (used_amount, burned_amount) =
+            self.token.internal_ft_resolve_transfer(&sender_id, receiver_id, amount);
+        if used_amount > 0 {
+            FtEvent::FtTransfer {
+                old_owner_id: sender_id.clone(),
+                new_owner_id,
+                amount: used_amount.into(),
+                memo: None,
+            }
+            .emit();
+        }
+        if burned_amount > 0 {
+            self.on_tokens_burned(sender_id, burned_amount);
+        }
+        used_amount.into()
+    }
 }
 
-near_contract_standards::impl_fungible_token_core!(Contract, token, on_tokens_burned);
-near_contract_standards::impl_fungible_token_storage!(Contract, token, on_account_closed);
+#[near_bindgen]
+impl StorageManagement for Contract {
+    #[payable]
+    fn storage_deposit(
+        &mut self,
+        account_id: Option<ValidAccountId>,
+        registration_only: Option<bool>,
+    ) -> StorageBalance {
+        self.token.storage_deposit(account_id, registration_only)
+    }
+
+    #[payable]
+    fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        assert!(!self.paused, "Transfers are paused");
+        self.token.storage_withdraw(amount)
+    }
+
+    #[payable]
+    fn storage_unregister(&mut self, force: Option<bool>) -> bool {
+        if let Some((account_id, balance)) = self.token.internal_storage_unregister(force) {
+            self.on_account_closed(account_id, balance);
+            true
+        } else {
+            false
+        }
+    }
+
+    fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        self.token.storage_balance_bounds()
+    }
+
+    fn storage_balance_of(&self, account_id: ValidAccountId) -> Option<StorageBalance> {
+        self.token.storage_balance_of(account_id)
+    }
+}
 
 #[near_bindgen]
 impl FungibleTokenMetadataProvider for Contract {
@@ -97,7 +409,7 @@ impl FungibleTokenMetadataProvider for Contract {
 
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
-    use near_sdk::test_utils::{accounts, VMContextBuilder};
+    use near_sdk::test_utils::{accounts, get_logs, VMContextBuilder};
     use near_sdk::MockedBlockchain;
     use near_sdk::{testing_env, Balance};
 
@@ -118,7 +430,7 @@ mod tests {
     fn test_new() {
         let mut context = get_context(accounts(1));
         testing_env!(context.build());
-        let contract = Contract::new_default_meta(accounts(1).into(), TOTAL_SUPPLY.into());
+        let contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
         testing_env!(context.is_view(true).build());
         assert_eq!(contract.ft_total_supply().0, TOTAL_SUPPLY);
         assert_eq!(contract.ft_balance_of(accounts(1)).0, TOTAL_SUPPLY);
@@ -136,7 +448,7 @@ mod tests {
     fn test_transfer() {
         let mut context = get_context(accounts(2));
         testing_env!(context.build());
-        let mut contract = Contract::new_default_meta(accounts(2).into(), TOTAL_SUPPLY.into());
+        let mut contract = Contract::new_default_meta(accounts(2), TOTAL_SUPPLY.into());
         testing_env!(context
             .storage_usage(env::storage_usage())
             .attached_deposit(contract.storage_balance_bounds().min.into())
@@ -162,4 +474,271 @@ mod tests {
         assert_eq!(contract.ft_balance_of(accounts(2)).0, (TOTAL_SUPPLY - transfer_amount));
         assert_eq!(contract.ft_balance_of(accounts(1)).0, transfer_amount);
     }
+
+    /// Parses the most recently logged `EVENT_JSON:{...}` line into its
+    /// envelope JSON, for asserting on NEP-297 event shape/content.
+    fn last_event() -> near_sdk::serde_json::Value {
+        let log = get_logs().pop().expect("expected an event to have been logged");
+        let json = log.strip_prefix("EVENT_JSON:").expect("expected an EVENT_JSON log");
+        near_sdk::serde_json::from_str(json).expect("event payload should be valid JSON")
+    }
+
+    #[test]
+    fn test_ft_mint_emits_event() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+        contract.grant_role(accounts(2), Role::Minter);
+
+        testing_env!(context.predecessor_account_id(accounts(2)).build());
+        contract.ft_mint(accounts(3), 1_000.into());
+
+        let event = last_event();
+        assert_eq!(event["standard"], "nep141");
+        assert_eq!(event["event"], "ft_mint");
+        assert_eq!(event["data"][0]["owner_id"], accounts(3).to_string());
+        assert_eq!(event["data"][0]["amount"], "1000");
+    }
+
+    #[test]
+    fn test_ft_burn_emits_event() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+        contract.grant_role(accounts(1), Role::Minter);
+        contract.grant_role(accounts(2), Role::Burner);
+
+        testing_env!(context.predecessor_account_id(accounts(1)).build());
+        contract.ft_mint(accounts(2), 1_000.into());
+
+        testing_env!(context.predecessor_account_id(accounts(2)).build());
+        contract.ft_burn(400.into());
+
+        let event = last_event();
+        assert_eq!(event["standard"], "nep141");
+        assert_eq!(event["event"], "ft_burn");
+        assert_eq!(event["data"][0]["owner_id"], accounts(2).to_string());
+        assert_eq!(event["data"][0]["amount"], "400");
+    }
+
+    #[test]
+    fn test_ft_transfer_emits_event() {
+        let mut context = get_context(accounts(2));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(2), TOTAL_SUPPLY.into());
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(contract.storage_balance_bounds().min.into())
+            .predecessor_account_id(accounts(1))
+            .build());
+        contract.storage_deposit(None, None);
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(1)
+            .predecessor_account_id(accounts(2))
+            .build());
+        let transfer_amount = TOTAL_SUPPLY / 3;
+        contract.ft_transfer(accounts(1), transfer_amount.into(), None);
+
+        let event = last_event();
+        assert_eq!(event["standard"], "nep141");
+        assert_eq!(event["event"], "ft_transfer");
+        assert_eq!(event["data"][0]["old_owner_id"], accounts(2).to_string());
+        assert_eq!(event["data"][0]["new_owner_id"], accounts(1).to_string());
+        assert_eq!(event["data"][0]["amount"], transfer_amount.to_string());
+    }
+
+    #[test]
+    fn test_near_deposit_and_withdraw() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+
+        let storage_cost = contract.storage_balance_bounds().min.0;
+        let deposit = storage_cost + 1_000_000;
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .predecessor_account_id(accounts(2))
+            .attached_deposit(deposit)
+            .build());
+        contract.near_deposit();
+
+        testing_env!(context.is_view(true).attached_deposit(0).build());
+        assert_eq!(contract.ft_balance_of(accounts(2)).0, deposit - storage_cost);
+
+        testing_env!(context
+            .is_view(false)
+            .storage_usage(env::storage_usage())
+            .account_balance(env::account_balance())
+            .predecessor_account_id(accounts(2))
+            .attached_deposit(1)
+            .build());
+        contract.near_withdraw((deposit - storage_cost).into());
+
+        testing_env!(context.is_view(true).attached_deposit(0).build());
+        assert_eq!(contract.ft_balance_of(accounts(2)).0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "The account doesn't have enough balance")]
+    fn test_near_withdraw_insufficient_balance_panics() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .predecessor_account_id(accounts(2))
+            .attached_deposit(contract.storage_balance_bounds().min.0)
+            .build());
+        contract.near_deposit();
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .predecessor_account_id(accounts(2))
+            .attached_deposit(1)
+            .build());
+        contract.near_withdraw(TOTAL_SUPPLY.into());
+    }
+
+    #[test]
+    fn test_mint_and_burn_round_trip() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+
+        // accounts(1) is the owner and holds Admin from `new`.
+        testing_env!(context.predecessor_account_id(accounts(1)).build());
+        contract.grant_role(accounts(2), Role::Minter);
+        contract.grant_role(accounts(3), Role::Burner);
+
+        testing_env!(context.predecessor_account_id(accounts(2)).build());
+        contract.ft_mint(accounts(3), 1_000.into());
+
+        testing_env!(context.is_view(true).build());
+        assert_eq!(contract.ft_balance_of(accounts(3)).0, 1_000);
+
+        testing_env!(context.is_view(false).predecessor_account_id(accounts(3)).build());
+        contract.ft_burn(400.into());
+
+        testing_env!(context.is_view(true).build());
+        assert_eq!(contract.ft_balance_of(accounts(3)).0, 600);
+    }
+
+    #[test]
+    #[should_panic(expected = "is missing the Minter role")]
+    fn test_revoke_role_removes_minter() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+
+        // accounts(1) is the owner and holds Admin from `new`.
+        testing_env!(context.predecessor_account_id(accounts(1)).build());
+        contract.grant_role(accounts(2), Role::Minter);
+        contract.revoke_role(accounts(2), Role::Minter);
+
+        testing_env!(context.predecessor_account_id(accounts(2)).build());
+        contract.ft_mint(accounts(3), 1.into());
+    }
+
+    #[test]
+    #[should_panic(expected = "is missing the Minter role")]
+    fn test_ft_mint_requires_minter_role() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+
+        testing_env!(context.predecessor_account_id(accounts(2)).build());
+        contract.ft_mint(accounts(2), 1.into());
+    }
+
+    #[test]
+    fn test_pause_and_unpause_flag() {
+        let context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+        assert!(!contract.is_paused());
+
+        contract.pause();
+        assert!(contract.is_paused());
+
+        contract.unpause();
+        assert!(!contract.is_paused());
+    }
+
+    #[test]
+    #[should_panic(expected = "is missing the Admin role")]
+    fn test_pause_requires_admin() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+
+        testing_env!(context.predecessor_account_id(accounts(2)).build());
+        contract.pause();
+    }
+
+    #[test]
+    #[should_panic(expected = "Transfers are paused")]
+    fn test_ft_mint_blocked_while_paused() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+        contract.grant_role(accounts(2), Role::Minter);
+        contract.pause();
+
+        testing_env!(context.predecessor_account_id(accounts(2)).build());
+        contract.ft_mint(accounts(2), 1.into());
+    }
+
+    #[test]
+    #[should_panic(expected = "Transfers are paused")]
+    fn test_near_withdraw_blocked_while_paused() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+        contract.pause();
+
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.near_withdraw(1.into());
+    }
+
+    #[test]
+    #[should_panic(expected = "Transfers are paused")]
+    fn test_near_deposit_blocked_while_paused() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+        contract.pause();
+
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .attached_deposit(contract.storage_balance_bounds().min.0)
+            .build());
+        contract.near_deposit();
+    }
+
+    #[test]
+    #[should_panic(expected = "Transfers are paused")]
+    fn test_ft_transfer_blocked_while_paused() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+        contract.pause();
+
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.ft_transfer(accounts(2), 1.into(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Transfers are paused")]
+    fn test_storage_withdraw_blocked_while_paused() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1), TOTAL_SUPPLY.into());
+        contract.pause();
+
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.storage_withdraw(None);
+    }
 }
@@ -0,0 +1,78 @@
+/*!
+Test fixture standing in for a future, *modified* ChaveroCoin deployment used
+by `tests-upgrade/tests/upgrade.rs` to prove `migrate` carries state across a real
+layout change (not just a no-op re-init to an unmodified wasm).
+
+The `OldContract` shape below is a byte-for-byte mirror of `chaverocoin-ft`'s
+`Contract` as of the `owner-gated self-upgrade` change: `token`, `metadata`,
+`roles`, `paused`, `owner_id`, in that order. `Contract` here adds a single
+new field, `upgrade_epoch`, so `migrate` has an actual schema to bridge.
+*/
+use near_contract_standards::fungible_token::metadata::FungibleTokenMetadata;
+use near_contract_standards::fungible_token::FungibleToken;
+use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
+use near_sdk::collections::{LazyOption, LookupMap};
+use near_sdk::json_types::{ValidAccountId, U128};
+use near_sdk::serde::{Deserialize, Serialize};
+use near_sdk::{env, near_bindgen, AccountId, PanicOnDefault, PromiseOrValue};
+use std::collections::HashSet;
+
+near_sdk::setup_alloc!();
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub enum Role {
+    Admin,
+    Minter,
+    Burner,
+}
+
+/// Mirrors the pre-upgrade `Contract` layout so its Borsh bytes can be read
+/// back out of storage via `env::state_read`.
+#[derive(BorshDeserialize, BorshSerialize)]
+struct OldContract {
+    token: FungibleToken,
+    metadata: LazyOption<FungibleTokenMetadata>,
+    roles: LookupMap<AccountId, HashSet<Role>>,
+    paused: bool,
+    owner_id: AccountId,
+}
+
+#[near_bindgen]
+#[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
+pub struct Contract {
+    token: FungibleToken,
+    metadata: LazyOption<FungibleTokenMetadata>,
+    roles: LookupMap<AccountId, HashSet<Role>>,
+    paused: bool,
+    owner_id: AccountId,
+    /// New in this layout: counts how many times `migrate` has run.
+    upgrade_epoch: u64,
+}
+
+#[near_bindgen]
+impl Contract {
+    /// Re-initializes the contract after `upgrade` deploys this wasm,
+    /// carrying the old state over and starting `upgrade_epoch` at 1.
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let old: OldContract = env::state_read().expect("Failed to read the old contract state");
+        Self {
+            token: old.token,
+            metadata: old.metadata,
+            roles: old.roles,
+            paused: old.paused,
+            owner_id: old.owner_id,
+            upgrade_epoch: 1,
+        }
+    }
+
+    /// How many times this contract has gone through `migrate`. Only
+    /// present post-upgrade, which is what the test asserts on.
+    pub fn upgrade_epoch(&self) -> u64 {
+        self.upgrade_epoch
+    }
+}
+
+near_contract_standards::impl_fungible_token_core!(Contract, token);
+near_contract_standards::impl_fungible_token_storage!(Contract, token);
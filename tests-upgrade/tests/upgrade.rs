@@ -0,0 +1,71 @@
+//! End-to-end `upgrade`/`migrate` test for the ChaveroCoin fungible token.
+//!
+//! Upgrades a deployed v1 contract to the `ft_v2` fixture wasm (see
+//! `ft/tests/fixtures/ft_v2`), which adds a new `upgrade_epoch` field not
+//! present in the v1 layout — a genuine schema change, not a re-deploy of
+//! identical bytes — and asserts both that `migrate` carries balances
+//! across it and that a non-owner account cannot trigger the upgrade.
+//!
+//! Requires both wasms to already be built:
+//!   cargo build -p chaverocoin-ft --target wasm32-unknown-unknown --release
+//!   cargo build -p chaverocoin-ft-v2-fixture --target wasm32-unknown-unknown --release
+
+const WASM_V1_FILEPATH: &str = "../target/wasm32-unknown-unknown/release/chaverocoin_ft.wasm";
+const WASM_V2_FILEPATH: &str =
+    "../target/wasm32-unknown-unknown/release/chaverocoin_ft_v2_fixture.wasm";
+
+#[tokio::test]
+async fn test_upgrade_preserves_balances_and_rejects_non_owner() -> anyhow::Result<()> {
+    let worker = near_workspaces::sandbox().await?;
+    let wasm_v1 = std::fs::read(WASM_V1_FILEPATH)?;
+    let wasm_v2 = std::fs::read(WASM_V2_FILEPATH)?;
+    let contract = worker.dev_deploy(&wasm_v1).await?;
+
+    let owner = worker.root_account()?;
+    let other = worker.dev_create_account().await?;
+    let total_supply = "1000000";
+
+    owner
+        .call(contract.id(), "new_default_meta")
+        .args_json(serde_json::json!({
+            "owner_id": owner.id(),
+            "total_supply": total_supply,
+        }))
+        .transact()
+        .await?
+        .into_result()?;
+
+    // A non-owner account must not be able to upgrade the contract.
+    let rejected = other
+        .call(contract.id(), "upgrade")
+        .args(wasm_v2.clone())
+        .max_gas()
+        .transact()
+        .await?;
+    assert!(rejected.is_failure(), "non-owner upgrade should have been rejected");
+
+    // The owner upgrades to the v2 fixture, which adds an `upgrade_epoch`
+    // field absent from the v1 layout — this is the real layout change
+    // `migrate` has to bridge, not a no-op re-init to identical bytes.
+    owner
+        .call(contract.id(), "upgrade")
+        .args(wasm_v2)
+        .max_gas()
+        .transact()
+        .await?
+        .into_result()?;
+
+    // `migrate` ran and populated the new field.
+    let upgrade_epoch: u64 = contract.view("upgrade_epoch").await?.json()?;
+    assert_eq!(upgrade_epoch, 1);
+
+    // Balances from before the upgrade must have survived the code swap.
+    let balance_after: String = contract
+        .view("ft_balance_of")
+        .args_json(serde_json::json!({ "account_id": owner.id() }))
+        .await?
+        .json()?;
+    assert_eq!(balance_after, total_supply);
+
+    Ok(())
+}